@@ -0,0 +1,335 @@
+use alloc::vec::Vec;
+use digest::Digest;
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey};
+use primitive_types::{H160, H256};
+use sha3::Keccak256;
+
+use crate::{
+    ByteLength, Decodeable, Decoder, Encodeable, Encoder, Error, Nullifier, Result, Transaction,
+    VarInt,
+};
+
+/// An ECDSA signature over a transaction sighash, in the `(r, s, v)` form
+/// used by Ethereum-style `ecrecover`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    pub r: H256,
+    pub s: H256,
+    /// Recovery id, either `{27, 28}` or `{0, 1}`.
+    pub v: u8,
+}
+
+const SIGNATURE_BYTE_LENGTH: usize = 65; // 32 (r) + 32 (s) + 1 (v)
+
+impl ByteLength for Signature {
+    fn byte_length(&self) -> usize {
+        SIGNATURE_BYTE_LENGTH
+    }
+}
+
+impl crate::Encode for Signature {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        enc.write_h256(&self.r);
+        enc.write_h256(&self.s);
+        enc.write_u8(self.v);
+        Ok(())
+    }
+}
+
+impl crate::Decode for Signature {
+    /// Decodes a Signature from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if fewer than 65 bytes remain.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let r = dec.read_h256()?;
+        let s = dec.read_h256()?;
+        let v = dec.read_u8()?;
+        Ok(Signature { r, s, v })
+    }
+}
+
+impl Encodeable for Signature {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(SIGNATURE_BYTE_LENGTH);
+        let mut enc = Encoder::new(&mut bytes);
+        <Signature as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for Signature {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut dec = Decoder::new(bytes);
+        <Signature as crate::Decode>::decode(&mut dec)
+    }
+}
+
+/// Recovers the signing address from a `sighash` and its `Signature`, the
+/// way an `ecrecover` precompile does.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidSignature` if `v` is not in `{0, 1, 27, 28}`, or if
+/// the signature bytes do not recover to a valid public key.
+pub fn recover(sighash: H256, sig: &Signature) -> Result<H160> {
+    let recovery_id = match sig.v {
+        27 | 0 => RecoveryId::from_byte(0).ok_or(Error::InvalidSignature)?,
+        28 | 1 => RecoveryId::from_byte(1).ok_or(Error::InvalidSignature)?,
+        _ => return Err(Error::InvalidSignature),
+    };
+
+    let mut rs = [0u8; 64];
+    rs[..32].copy_from_slice(sig.r.as_bytes());
+    rs[32..].copy_from_slice(sig.s.as_bytes());
+    let signature = EcdsaSignature::from_slice(&rs).map_err(|_| Error::InvalidSignature)?;
+
+    let verifying_key =
+        VerifyingKey::recover_from_prehash(sighash.as_bytes(), &signature, recovery_id)
+            .map_err(|_| Error::InvalidSignature)?;
+
+    let uncompressed = verifying_key.to_encoded_point(false);
+    let mut hasher = Keccak256::new();
+    hasher.update(&uncompressed.as_bytes()[1..]);
+    let hash = hasher.finalize();
+
+    Ok(H160::from_slice(&hash[12..]))
+}
+
+/// A `Transaction` paired with one `Signature` per `Nullifier::Public` input.
+///
+/// `signatures[i]` authorizes `transaction.inputs[i]` and is `None` for
+/// `Nullifier::Private` inputs, which need no signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTransaction {
+    pub transaction: Transaction,
+    pub signatures: Vec<Option<Signature>>,
+}
+
+impl SignedTransaction {
+    /// Computes the sighash: a digest of the canonical encoding of the
+    /// unsigned `transaction`.
+    pub fn sighash<D: Digest>(&self) -> H256 {
+        let mut hasher = D::new();
+        hasher.update(self.transaction.encode());
+        H256::from_slice(&hasher.finalize())
+    }
+
+    /// Verifies that the signature attached to the `Nullifier::Public` input
+    /// at `index` recovers to `owner`, the address recorded on the `Output`
+    /// it spends.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidSignature` if `index` is not a
+    /// `Nullifier::Public` input, has no attached signature, or the
+    /// signature recovers to an address other than `owner`.
+    pub fn verify_public_input<D: Digest>(&self, index: usize, owner: H160) -> Result<()> {
+        match self.transaction.inputs.get(index) {
+            Some(Nullifier::Public(_)) => {}
+            _ => return Err(Error::InvalidSignature),
+        }
+
+        let signature = self
+            .signatures
+            .get(index)
+            .and_then(|signature| signature.as_ref())
+            .ok_or(Error::InvalidSignature)?;
+
+        let recovered = recover(self.sighash::<D>(), signature)?;
+        if recovered != owner {
+            return Err(Error::InvalidSignature);
+        }
+
+        Ok(())
+    }
+}
+
+impl ByteLength for SignedTransaction {
+    fn byte_length(&self) -> usize {
+        self.transaction.byte_length()
+            + VarInt::from(self.signatures.len()).byte_length()
+            + self
+                .signatures
+                .iter()
+                .map(|signature| match signature {
+                    Some(signature) => 1 + signature.byte_length(),
+                    None => 1,
+                })
+                .sum::<usize>()
+    }
+}
+
+impl crate::Encode for SignedTransaction {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        <Transaction as crate::Encode>::encode(&self.transaction, enc)?;
+
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.signatures.len()), enc)?;
+        for signature in &self.signatures {
+            match signature {
+                Some(signature) => {
+                    enc.write_u8(1);
+                    <Signature as crate::Encode>::encode(signature, enc)?;
+                }
+                None => enc.write_u8(0),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl crate::Decode for SignedTransaction {
+    /// Decodes a SignedTransaction from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if the input is exhausted or an
+    /// authorization tag is neither `0` nor `1`.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let transaction = <Transaction as crate::Decode>::decode(dec)?;
+
+        // The smallest possible entry is a 1-byte `None` tag; clamp
+        // pre-allocation to what could actually fit in the remaining bytes,
+        // since `count` comes straight off the wire.
+        const MIN_SIGNATURE_SLOT_BYTE_LENGTH: usize = 1;
+        let count = <VarInt as crate::Decode>::decode(dec)?.as_usize();
+        let capacity = count.min(dec.remaining().len() / MIN_SIGNATURE_SLOT_BYTE_LENGTH);
+        let mut signatures = Vec::with_capacity(capacity);
+        for _ in 0..count {
+            let signature = match dec.read_u8()? {
+                0 => None,
+                1 => Some(<Signature as crate::Decode>::decode(dec)?),
+                _ => return Err(Error::FailedToDecode),
+            };
+            signatures.push(signature);
+        }
+
+        Ok(SignedTransaction {
+            transaction,
+            signatures,
+        })
+    }
+}
+
+impl Encodeable for SignedTransaction {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <SignedTransaction as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for SignedTransaction {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut dec = Decoder::new(bytes);
+        <SignedTransaction as crate::Decode>::decode(&mut dec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use k256::ecdsa::{SigningKey, VerifyingKey};
+    use primitive_types::{H160, U256};
+    use sha2::Sha256;
+
+    use crate::{Commitment, Nullifier, Output, OutputId};
+
+    fn owner_of(signing_key: &SigningKey) -> H160 {
+        let verifying_key = VerifyingKey::from(signing_key);
+        let uncompressed = verifying_key.to_encoded_point(false);
+        let mut hasher = Keccak256::new();
+        hasher.update(&uncompressed.as_bytes()[1..]);
+        H160::from_slice(&hasher.finalize()[12..])
+    }
+
+    fn sign(signing_key: &SigningKey, sighash: H256) -> Signature {
+        let (sig, recovery_id): (EcdsaSignature, RecoveryId) =
+            signing_key.sign_prehash(sighash.as_bytes()).unwrap();
+        let bytes = sig.to_bytes();
+        Signature {
+            r: H256::from_slice(&bytes[..32]),
+            s: H256::from_slice(&bytes[32..]),
+            v: recovery_id.to_byte() + 27,
+        }
+    }
+
+    #[test]
+    fn test_recover_matches_signing_key() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        let owner = owner_of(&signing_key);
+        let sighash = H256::repeat_byte(9);
+
+        let signature = sign(&signing_key, sighash);
+        let recovered = recover(sighash, &signature).unwrap();
+
+        assert_eq!(recovered, owner);
+    }
+
+    #[test]
+    fn test_recover_rejects_invalid_v() {
+        let signature = Signature {
+            r: H256::zero(),
+            s: H256::zero(),
+            v: 99,
+        };
+        assert_eq!(
+            recover(H256::zero(), &signature),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_signed_transaction_verify_public_input() {
+        let signing_key = SigningKey::from_bytes(&[3u8; 32].into()).unwrap();
+        let owner = owner_of(&signing_key);
+
+        let transaction = Transaction {
+            inputs: alloc::vec![Nullifier::Public(OutputId {
+                txhash: H256::repeat_byte(1),
+                index: 0,
+            })],
+            outputs: alloc::vec![Commitment::Public(Output {
+                amount: U256::from(1u32),
+                asset: H256::zero(),
+                owner,
+            })],
+        };
+
+        let mut signed = SignedTransaction {
+            transaction,
+            signatures: alloc::vec![None],
+        };
+        let sighash = signed.sighash::<Sha256>();
+        signed.signatures[0] = Some(sign(&signing_key, sighash));
+
+        assert!(signed.verify_public_input::<Sha256>(0, owner).is_ok());
+        assert_eq!(
+            signed.verify_public_input::<Sha256>(0, H160::repeat_byte(0xAA)),
+            Err(Error::InvalidSignature)
+        );
+
+        let encoded = signed.encode();
+        let decoded = SignedTransaction::decode(&encoded).unwrap();
+        assert_eq!(signed, decoded);
+    }
+
+    #[test]
+    fn test_signed_transaction_decode_rejects_oversized_declared_length() {
+        // An empty transaction followed by a VarInt declaring ~2^56
+        // signatures with no backing bytes must fail cleanly instead of
+        // aborting on an oversized Vec::with_capacity.
+        let mut hostile = alloc::vec![0x00, 0x00]; // zero inputs, zero outputs
+        hostile.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        assert_eq!(
+            SignedTransaction::decode(&hostile),
+            Err(Error::FailedToDecode)
+        );
+    }
+}
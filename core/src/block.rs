@@ -0,0 +1,326 @@
+use alloc::vec::Vec;
+use digest::Digest;
+use primitive_types::{H256, U256};
+
+use crate::{
+    ByteLength, Decodeable, Decoder, Encodeable, Encoder, Error, MerkleTree, Result, Transaction,
+    VarInt,
+};
+
+/// The metadata of a `Block`: everything needed to verify its proof-of-work
+/// without the transactions themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub version: u32,
+    pub prev_blockhash: H256,
+    /// The Merkle root of the block's transactions, as built by `Block::new`.
+    pub merkle_root: H256,
+    pub time: u32,
+    /// The proof-of-work target in Bitcoin's compact ("nBits") form.
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+const BLOCK_HEADER_BYTE_LENGTH: usize = 4 + 32 + 32 + 4 + 4 + 4;
+
+impl BlockHeader {
+    /// Decodes the compact `bits` field into the full-width target, the way
+    /// Bitcoin's `nBits` encoding does.
+    ///
+    /// Returns zero if the mantissa's sign bit is set, which Bitcoin treats
+    /// as an invalid/negative target.
+    pub fn target(&self) -> U256 {
+        let exponent = self.bits >> 24;
+        let mantissa = self.bits & 0x00FF_FFFF;
+
+        if mantissa > 0x7F_FFFF {
+            return U256::zero();
+        }
+
+        if exponent <= 3 {
+            U256::from(mantissa) >> (8 * (3 - exponent))
+        } else {
+            U256::from(mantissa) << (8 * (exponent - 3))
+        }
+    }
+
+    /// Computes the header hash: a double-digest of the header's canonical encoding.
+    pub fn hash<D: Digest>(&self) -> H256 {
+        let mut outer = D::new();
+        outer.update(D::digest(self.encode()));
+        H256::from_slice(&outer.finalize())
+    }
+
+    /// Validates this header the way a lightweight (SPV) client would: its
+    /// `target()` must match `required`, and its hash must not exceed it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::BadTarget` if `self.target()` does not equal
+    /// `required`, or `Error::BadProofOfWork` if the header hash exceeds the
+    /// target.
+    pub fn spv_validate<D: Digest>(&self, required: &U256) -> Result<()> {
+        let target = self.target();
+        if &target != required {
+            return Err(Error::BadTarget);
+        }
+
+        let hash = U256::from_big_endian(self.hash::<D>().as_bytes());
+        if hash > target {
+            return Err(Error::BadProofOfWork);
+        }
+
+        Ok(())
+    }
+}
+
+impl ByteLength for BlockHeader {
+    fn byte_length(&self) -> usize {
+        BLOCK_HEADER_BYTE_LENGTH
+    }
+}
+
+impl crate::Encode for BlockHeader {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        enc.write_u32_be(self.version);
+        enc.write_h256(&self.prev_blockhash);
+        enc.write_h256(&self.merkle_root);
+        enc.write_u32_be(self.time);
+        enc.write_u32_be(self.bits);
+        enc.write_u32_be(self.nonce);
+        Ok(())
+    }
+}
+
+impl crate::Decode for BlockHeader {
+    /// Decodes a BlockHeader from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if fewer than 80 bytes remain.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let version = dec.read_u32_be()?;
+        let prev_blockhash = dec.read_h256()?;
+        let merkle_root = dec.read_h256()?;
+        let time = dec.read_u32_be()?;
+        let bits = dec.read_u32_be()?;
+        let nonce = dec.read_u32_be()?;
+        Ok(BlockHeader {
+            version,
+            prev_blockhash,
+            merkle_root,
+            time,
+            bits,
+            nonce,
+        })
+    }
+}
+
+impl Encodeable for BlockHeader {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(BLOCK_HEADER_BYTE_LENGTH);
+        let mut enc = Encoder::new(&mut bytes);
+        <BlockHeader as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for BlockHeader {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut dec = Decoder::new(bytes);
+        <BlockHeader as crate::Decode>::decode(&mut dec)
+    }
+}
+
+/// A `BlockHeader` paired with the transactions it commits to via `merkle_root`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub header: BlockHeader,
+    pub transactions: Vec<Transaction>,
+}
+
+impl Block {
+    /// Builds a block, populating `header.merkle_root` from the Merkle
+    /// accumulator over `transactions`.
+    pub fn new<D: Digest>(mut header: BlockHeader, transactions: Vec<Transaction>) -> Self {
+        header.merkle_root = Self::merkle_root::<D>(&transactions);
+        Block {
+            header,
+            transactions,
+        }
+    }
+
+    /// Computes the Merkle root over `transactions`' canonical encodings.
+    pub fn merkle_root<D: Digest>(transactions: &[Transaction]) -> H256 {
+        let leaves: Vec<H256> = transactions
+            .iter()
+            .map(|transaction| H256::from_slice(&D::digest(transaction.encode())))
+            .collect();
+        MerkleTree::<D>::new(&leaves).root().unwrap_or_default()
+    }
+}
+
+impl ByteLength for Block {
+    fn byte_length(&self) -> usize {
+        self.header.byte_length()
+            + VarInt::from(self.transactions.len()).byte_length()
+            + self
+                .transactions
+                .iter()
+                .map(|transaction| transaction.byte_length())
+                .sum::<usize>()
+    }
+}
+
+impl crate::Encode for Block {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        <BlockHeader as crate::Encode>::encode(&self.header, enc)?;
+
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.transactions.len()), enc)?;
+        for transaction in &self.transactions {
+            <Transaction as crate::Encode>::encode(transaction, enc)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl crate::Decode for Block {
+    /// Decodes a Block from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if the input is exhausted.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let header = <BlockHeader as crate::Decode>::decode(dec)?;
+
+        // The smallest possible Transaction encoding is two 1-byte VarInt
+        // lengths (zero inputs, zero outputs); clamp pre-allocation to what
+        // could actually fit in the remaining bytes, since `count` comes
+        // straight off the wire.
+        const MIN_TRANSACTION_BYTE_LENGTH: usize = 2;
+        let count = <VarInt as crate::Decode>::decode(dec)?.as_usize();
+        let capacity = count.min(dec.remaining().len() / MIN_TRANSACTION_BYTE_LENGTH);
+        let mut transactions = Vec::with_capacity(capacity);
+        for _ in 0..count {
+            transactions.push(<Transaction as crate::Decode>::decode(dec)?);
+        }
+
+        Ok(Block {
+            header,
+            transactions,
+        })
+    }
+}
+
+impl Encodeable for Block {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <Block as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for Block {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut dec = Decoder::new(bytes);
+        <Block as crate::Decode>::decode(&mut dec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    fn header(bits: u32, nonce: u32) -> BlockHeader {
+        BlockHeader {
+            version: 1,
+            prev_blockhash: H256::zero(),
+            merkle_root: H256::zero(),
+            time: 0,
+            bits,
+            nonce,
+        }
+    }
+
+    #[test]
+    fn test_target_decodes_compact_bits() {
+        // Bitcoin's genesis block difficulty: 0x1d00ffff.
+        assert_eq!(
+            header(0x1d00ffff, 0).target(),
+            U256::from(0x00ffffu32) << (8 * (0x1d - 3))
+        );
+        // Exponent <= 3 shifts the mantissa right instead of left.
+        assert_eq!(header(0x02008000, 0).target(), U256::from(0x80u32));
+        // A mantissa with its sign bit set is treated as invalid.
+        assert_eq!(header(0x01800000, 0).target(), U256::zero());
+    }
+
+    #[test]
+    fn test_block_encode_decode_and_merkle_root() {
+        let transactions = alloc::vec![
+            Transaction {
+                inputs: alloc::vec![],
+                outputs: alloc::vec![],
+            },
+            Transaction {
+                inputs: alloc::vec![],
+                outputs: alloc::vec![],
+            },
+        ];
+        let block = Block::new::<Sha256>(header(0x207fffff, 0), transactions);
+
+        assert_eq!(
+            block.header.merkle_root,
+            Block::merkle_root::<Sha256>(&block.transactions)
+        );
+
+        let encoded = block.encode();
+        let decoded = Block::decode(&encoded).unwrap();
+        assert_eq!(block, decoded);
+    }
+
+    #[test]
+    fn test_spv_validate() {
+        let transactions = alloc::vec![];
+        let mut block = Block::new::<Sha256>(header(0x207fffff, 0), transactions);
+        let required = block.header.target();
+
+        // At the easiest representable target, a passing nonce is found
+        // almost immediately (odds roughly halve with every attempt).
+        while U256::from_big_endian(block.header.hash::<Sha256>().as_bytes()) > required {
+            block.header.nonce += 1;
+        }
+        assert!(block.header.spv_validate::<Sha256>(&required).is_ok());
+
+        // A mismatched required target is rejected before hashing.
+        assert_eq!(
+            block.header.spv_validate::<Sha256>(&U256::from(1u32)),
+            Err(Error::BadTarget)
+        );
+
+        // Shrinking the target below the header's actual hash fails PoW.
+        let hash = U256::from_big_endian(block.header.hash::<Sha256>().as_bytes());
+        block.header.bits = 0x03000001; // target == 1
+        let tiny_target = block.header.target();
+        assert!(tiny_target < hash);
+        assert_eq!(
+            block.header.spv_validate::<Sha256>(&tiny_target),
+            Err(Error::BadProofOfWork)
+        );
+    }
+
+    #[test]
+    fn test_block_decode_rejects_oversized_declared_length() {
+        // A valid header followed by a VarInt declaring ~2^56 transactions
+        // with no backing bytes must fail cleanly instead of aborting on an
+        // oversized Vec::with_capacity.
+        let mut hostile = header(0x207fffff, 0).encode();
+        hostile.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        assert_eq!(Block::decode(&hostile), Err(Error::FailedToDecode));
+    }
+}
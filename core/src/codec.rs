@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+use primitive_types::H256;
+
+use crate::{Error, Result};
+
+/// A streaming encoder that appends into a caller-supplied buffer.
+///
+/// Unlike `Encodeable::encode`, which allocates a fresh `Vec<u8>` per call,
+/// nested `Encode` implementations share the same `Encoder`, so a composite
+/// type writes its fields directly into the outer buffer in a single pass.
+pub struct Encoder<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl<'a> Encoder<'a> {
+    /// Wraps an existing buffer so encoding appends to it in place.
+    pub fn new(buf: &'a mut Vec<u8>) -> Self {
+        Encoder { buf }
+    }
+
+    /// Appends raw bytes to the buffer.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Appends a single byte to the buffer.
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    /// Appends a big-endian `u32` to the buffer.
+    pub fn write_u32_be(&mut self, value: u32) {
+        self.buf.extend_from_slice(&value.to_be_bytes());
+    }
+
+    /// Appends an `H256` to the buffer.
+    pub fn write_h256(&mut self, value: &H256) {
+        self.buf.extend_from_slice(value.as_bytes());
+    }
+}
+
+/// A streaming, bounds-checked decoder over a byte slice.
+///
+/// Each `read_*` method advances an internal cursor and returns
+/// `Error::FailedToDecode` instead of panicking when the slice is exhausted
+/// before the requested number of bytes is available.
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Creates a decoder positioned at the start of `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Decoder { bytes, position: 0 }
+    }
+
+    /// The current cursor position within the underlying byte slice.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The unconsumed tail of the underlying byte slice.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// Reads `n` bytes, advancing the cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if fewer than `n` bytes remain.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self.position.checked_add(n).ok_or(Error::FailedToDecode)?;
+        let slice = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(Error::FailedToDecode)?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    /// Reads a single byte, advancing the cursor.
+    pub fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    /// Reads a big-endian `u32`, advancing the cursor.
+    pub fn read_u32_be(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    /// Reads an `H256`, advancing the cursor.
+    pub fn read_h256(&mut self) -> Result<H256> {
+        Ok(H256::from_slice(self.read_bytes(32)?))
+    }
+}
+
+/// A trait for types that encode themselves into a streaming `Encoder`.
+///
+/// This is the allocation-free counterpart to `Encodeable`: implementors
+/// append directly into the caller's buffer instead of returning an owned
+/// `Vec<u8>`, so composite types encode their fields in one pass.
+pub trait Encode {
+    /// Encodes `self` into `enc`.
+    fn encode(&self, enc: &mut Encoder) -> Result<()>;
+}
+
+/// A trait for types that decode themselves from a streaming `Decoder`.
+///
+/// This is the bounds-checked counterpart to `Decodeable`: implementors read
+/// through the shared cursor instead of re-slicing and recomputing
+/// `byte_length()` to advance a manual cursor at every step.
+pub trait Decode: Sized {
+    /// Decodes an instance of `Self` from `dec`.
+    fn decode(dec: &mut Decoder) -> Result<Self>;
+}
@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 use digest::Digest;
 use primitive_types::{H160, H256, U256};
 
-use crate::{ByteLength, Decodeable, Encodeable, Error, Result};
+use crate::{ByteLength, Decodeable, Decoder, Encodeable, Encoder, Error, Result};
 
 /// Transparent unspent output
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,19 +39,47 @@ impl ByteLength for Output {
     }
 }
 
+impl crate::Encode for Output {
+    /// Encodes the Output's fields into `enc`.
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        enc.write_bytes(&self.amount.to_big_endian());
+        enc.write_h256(&self.asset);
+        enc.write_bytes(self.owner.as_bytes());
+        Ok(())
+    }
+}
+
+impl crate::Decode for Output {
+    /// Decodes an Output from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if fewer than 84 bytes remain.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let amount = U256::from_big_endian(dec.read_bytes(32)?);
+        let asset = dec.read_h256()?;
+        let owner = H160::from_slice(dec.read_bytes(20)?);
+        Ok(Output {
+            amount,
+            asset,
+            owner,
+        })
+    }
+}
+
 impl Encodeable for Output {
-    /// Encodes the UnspentOutput into a byte vector.
+    /// Encodes the Output into a byte vector.
     fn encode(&self) -> Vec<u8> {
-        let mut bytes = Vec::new();
-        bytes.extend_from_slice(&self.amount.to_big_endian());
-        bytes.extend_from_slice(self.asset.as_bytes());
-        bytes.extend_from_slice(self.owner.as_bytes());
+        let mut bytes = Vec::with_capacity(UNSPENT_OUTPUT_BYTE_LENGTH);
+        let mut enc = Encoder::new(&mut bytes);
+        <Output as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
         bytes
     }
 }
 
 impl Decodeable for Output {
-    /// Decodes a byte slice into an UnspentOutput.
+    /// Decodes a byte slice into an Output.
     ///
     /// # Errors
     ///
@@ -60,18 +88,8 @@ impl Decodeable for Output {
     where
         Self: Sized,
     {
-        if bytes.len() < UNSPENT_OUTPUT_BYTE_LENGTH {
-            return Err(Error::FailedToDecode);
-        }
-
-        let amount = U256::from_big_endian(&bytes[..32]);
-        let asset = H256::from_slice(&bytes[32..64]);
-        let owner = H160::from_slice(&bytes[64..84]);
-        Ok(Output {
-            amount,
-            asset,
-            owner,
-        })
+        let mut dec = Decoder::new(bytes);
+        <Output as crate::Decode>::decode(&mut dec)
     }
 }
 
@@ -109,51 +127,38 @@ impl ByteLength for Commitment {
     }
 }
 
-impl Encodeable for Commitment {
-    /// Encodes the Commitment into a byte vector.
-    fn encode(&self) -> Vec<u8> {
+impl crate::Encode for Commitment {
+    /// Encodes the Commitment into `enc`.
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
         match self {
             Commitment::Private(commitment) => {
-                let mut bytes = Vec::with_capacity(33);
-                bytes.push(1u8);
-                bytes.extend_from_slice(commitment.0.as_bytes());
-                bytes
+                enc.write_u8(1);
+                enc.write_h256(&commitment.0);
             }
             Commitment::Public(output) => {
-                let mut bytes = Vec::with_capacity(85);
-                bytes.push(2u8);
-                bytes.extend(output.encode());
-                bytes
+                enc.write_u8(2);
+                <Output as crate::Encode>::encode(output, enc)?;
             }
         }
+        Ok(())
     }
 }
 
-impl Decodeable for Commitment {
-    /// Decodes a byte slice into a Commitment.
+impl crate::Decode for Commitment {
+    /// Decodes a Commitment from `dec`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the input is empty or if the commitment type is unsupported.
-    fn decode(bytes: &[u8]) -> Result<Self>
-    where
-        Self: Sized,
-    {
-        if bytes.is_empty() {
-            return Err(Error::FailedToDecode);
-        }
-
-        match bytes[0] {
+    /// Returns `Error::FailedToDecode` if the input is exhausted, or
+    /// `Error::UnsupportedCommitmentType` if the discriminant is unrecognized.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        match dec.read_u8()? {
             1 => {
-                if bytes.len() < 33 {
-                    return Err(Error::FailedToDecode);
-                }
-
-                let commitment = H256::from_slice(&bytes[1..33]);
+                let commitment = dec.read_h256()?;
                 Ok(Commitment::Private(PrivateCommitment(commitment)))
             }
             2 => {
-                let output = Output::decode(&bytes[1..])?;
+                let output = <Output as crate::Decode>::decode(dec)?;
                 Ok(Commitment::Public(output))
             }
             _ => Err(Error::UnsupportedCommitmentType),
@@ -161,6 +166,32 @@ impl Decodeable for Commitment {
     }
 }
 
+impl Encodeable for Commitment {
+    /// Encodes the Commitment into a byte vector.
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <Commitment as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for Commitment {
+    /// Decodes a byte slice into a Commitment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is empty or if the commitment type is unsupported.
+    fn decode(bytes: &[u8]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut dec = Decoder::new(bytes);
+        <Commitment as crate::Decode>::decode(&mut dec)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
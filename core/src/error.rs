@@ -7,6 +7,12 @@ pub enum Error {
     UnsupportedCommitmentType,
     /// Indicates an unsupported nullifier type was encountered.
     UnsupportedNullifierType,
+    /// Indicates a signature was malformed or did not recover to the expected address.
+    InvalidSignature,
+    /// Indicates a block header's `target()` did not match the required target.
+    BadTarget,
+    /// Indicates a block header's hash exceeded its proof-of-work target.
+    BadProofOfWork,
 }
 
 /// A type alias for `Result` with the error type set to our custom `Error`.
@@ -14,6 +14,21 @@ pub use nullifier::*;
 mod commitment;
 pub use commitment::*;
 
+mod varint;
+pub use varint::*;
+
+mod codec;
+pub use codec::*;
+
+mod merkle;
+pub use merkle::*;
+
+mod authorization;
+pub use authorization::*;
+
+mod block;
+pub use block::*;
+
 mod prelude;
 pub use prelude::*;
 
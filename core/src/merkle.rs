@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use digest::Digest;
+use primitive_types::H256;
+
+use crate::{ByteLength, Decodeable, Decoder, Encodeable, Encoder, Error, Result, VarInt};
+
+/// Which side of the accumulator a sibling hash sits on when folding a
+/// `MerkleProof` upward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The sibling is the left child; the accumulator is the right child.
+    Left,
+    /// The sibling is the right child; the accumulator is the left child.
+    Right,
+}
+
+/// A Merkle accumulator over an ordered list of leaf hashes.
+///
+/// Mirrors the `Digest` use in `Output::commitment`: builds a binary tree by
+/// repeatedly hashing adjacent pairs with `D`, duplicating the final node of
+/// an odd-sized level, until a single root remains.
+pub struct MerkleTree<D: Digest> {
+    /// Every level of the tree, from leaves (`levels[0]`) to the root.
+    levels: Vec<Vec<H256>>,
+    _digest: PhantomData<D>,
+}
+
+impl<D: Digest> MerkleTree<D> {
+    /// Builds the tree from an ordered list of leaf hashes.
+    pub fn new(leaves: &[H256]) -> Self {
+        let mut levels = Vec::new();
+        levels.push(leaves.to_vec());
+
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let current = levels.last().unwrap();
+            let mut next = Vec::with_capacity(current.len().div_ceil(2));
+
+            let mut pairs = current.chunks(2);
+            for pair in &mut pairs {
+                let left = pair[0];
+                let right = *pair.get(1).unwrap_or(&left);
+                next.push(Self::hash_pair(&left, &right));
+            }
+
+            levels.push(next);
+        }
+
+        MerkleTree {
+            levels,
+            _digest: PhantomData,
+        }
+    }
+
+    fn hash_pair(left: &H256, right: &H256) -> H256 {
+        let mut hasher = D::new();
+        hasher.update(left.as_bytes());
+        hasher.update(right.as_bytes());
+        H256::from_slice(&hasher.finalize())
+    }
+
+    /// The Merkle root, or `None` if the tree has no leaves.
+    pub fn root(&self) -> Option<H256> {
+        self.levels.last().and_then(|level| level.first()).copied()
+    }
+
+    /// Builds an inclusion proof for the leaf at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds for the leaf level.
+    pub fn prove(&self, index: usize) -> MerkleProof {
+        let leaf_count = self.levels[0].len();
+        assert!(index < leaf_count, "index out of bounds for Merkle tree");
+
+        let mut siblings = Vec::with_capacity(self.levels.len() - 1);
+        let mut idx = index;
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            let (sibling_idx, direction) = if idx.is_multiple_of(2) {
+                (idx + 1, Direction::Right)
+            } else {
+                (idx - 1, Direction::Left)
+            };
+            let sibling = level.get(sibling_idx).copied().unwrap_or(level[idx]);
+            siblings.push((sibling, direction));
+            idx /= 2;
+        }
+
+        MerkleProof {
+            leaf_count,
+            index,
+            siblings,
+        }
+    }
+
+    /// Verifies that `leaf` is included under `root`, folding the proof's
+    /// sibling hashes upward with `D`.
+    pub fn verify(leaf: H256, proof: &MerkleProof, root: H256) -> bool {
+        let mut acc = leaf;
+        for (sibling, direction) in &proof.siblings {
+            acc = match direction {
+                Direction::Left => Self::hash_pair(sibling, &acc),
+                Direction::Right => Self::hash_pair(&acc, sibling),
+            };
+        }
+        acc == root
+    }
+}
+
+/// An inclusion proof that a leaf is a member of a Merkle tree with a given root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// The number of leaves in the tree the proof was generated from.
+    pub leaf_count: usize,
+    /// The index of the proven leaf.
+    pub index: usize,
+    /// Ordered sibling hashes from leaf to root, each tagged with the side it sits on.
+    pub siblings: Vec<(H256, Direction)>,
+}
+
+impl ByteLength for MerkleProof {
+    fn byte_length(&self) -> usize {
+        VarInt::from(self.leaf_count).byte_length()
+            + VarInt::from(self.index).byte_length()
+            + VarInt::from(self.siblings.len()).byte_length()
+            + self.siblings.len() * 33 // 32 (sibling hash) + 1 (direction)
+    }
+}
+
+impl crate::Encode for MerkleProof {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.leaf_count), enc)?;
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.index), enc)?;
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.siblings.len()), enc)?;
+
+        for (sibling, direction) in &self.siblings {
+            enc.write_h256(sibling);
+            enc.write_u8(match direction {
+                Direction::Left => 0,
+                Direction::Right => 1,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl crate::Decode for MerkleProof {
+    /// Decodes a MerkleProof from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if the input is exhausted or a
+    /// direction byte is neither `0` nor `1`.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let leaf_count = <VarInt as crate::Decode>::decode(dec)?.as_usize();
+        let index = <VarInt as crate::Decode>::decode(dec)?.as_usize();
+        let sibling_count = <VarInt as crate::Decode>::decode(dec)?.as_usize();
+
+        // Each sibling entry is a 32-byte H256 plus a 1-byte direction; clamp
+        // pre-allocation to what could actually fit in the remaining bytes,
+        // since `sibling_count` comes straight off the wire.
+        const SIBLING_BYTE_LENGTH: usize = 33;
+        let siblings_capacity = sibling_count.min(dec.remaining().len() / SIBLING_BYTE_LENGTH);
+        let mut siblings = Vec::with_capacity(siblings_capacity);
+        for _ in 0..sibling_count {
+            let sibling = dec.read_h256()?;
+            let direction = match dec.read_u8()? {
+                0 => Direction::Left,
+                1 => Direction::Right,
+                _ => return Err(Error::FailedToDecode),
+            };
+            siblings.push((sibling, direction));
+        }
+
+        Ok(MerkleProof {
+            leaf_count,
+            index,
+            siblings,
+        })
+    }
+}
+
+impl Encodeable for MerkleProof {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <MerkleProof as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for MerkleProof {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut dec = Decoder::new(bytes);
+        <MerkleProof as crate::Decode>::decode(&mut dec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    fn leaf(byte: u8) -> H256 {
+        H256::repeat_byte(byte)
+    }
+
+    #[test]
+    fn test_merkle_root_pairs_and_duplicates_odd_node() {
+        let leaves = [leaf(1), leaf(2), leaf(3)];
+
+        let tree = MerkleTree::<Sha256>::new(&leaves);
+
+        let pair = MerkleTree::<Sha256>::hash_pair(&leaves[0], &leaves[1]);
+        let duplicated = MerkleTree::<Sha256>::hash_pair(&leaves[2], &leaves[2]);
+        let expected_root = MerkleTree::<Sha256>::hash_pair(&pair, &duplicated);
+
+        assert_eq!(tree.root(), Some(expected_root));
+    }
+
+    #[test]
+    fn test_merkle_prove_and_verify_roundtrip() {
+        let leaves = [leaf(1), leaf(2), leaf(3), leaf(4), leaf(5)];
+        let tree = MerkleTree::<Sha256>::new(&leaves);
+        let root = tree.root().unwrap();
+
+        for (index, &value) in leaves.iter().enumerate() {
+            let proof = tree.prove(index);
+            assert!(MerkleTree::<Sha256>::verify(value, &proof, root));
+        }
+
+        // A proof against a different leaf must not verify.
+        let bad_proof = tree.prove(0);
+        assert!(!MerkleTree::<Sha256>::verify(leaf(9), &bad_proof, root));
+    }
+
+    #[test]
+    fn test_merkle_proof_encode_decode() {
+        let leaves = [leaf(1), leaf(2), leaf(3)];
+        let tree = MerkleTree::<Sha256>::new(&leaves);
+        let proof = tree.prove(2);
+
+        let encoded = proof.encode();
+        let decoded = MerkleProof::decode(&encoded).unwrap();
+        assert_eq!(proof, decoded);
+    }
+
+    #[test]
+    fn test_merkle_proof_decode_rejects_oversized_declared_length() {
+        // leaf_count = 0, index = 0, then a VarInt declaring ~2^56 siblings
+        // with no backing bytes; must fail cleanly instead of aborting on an
+        // oversized Vec::with_capacity.
+        let mut hostile = alloc::vec![0x00, 0x00];
+        hostile.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        assert_eq!(MerkleProof::decode(&hostile), Err(Error::FailedToDecode));
+    }
+}
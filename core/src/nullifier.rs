@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 use primitive_types::H256;
 
-use crate::{ByteLength, Decodeable, Encodeable, Error, Result};
+use crate::{ByteLength, Decodeable, Decoder, Encodeable, Encoder, Error, Result};
 
 /// Represents the unique identifier of an output in a transaction.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,6 +22,28 @@ impl ByteLength for OutputId {
     }
 }
 
+impl crate::Encode for OutputId {
+    /// Encodes the OutputId's fields into `enc`.
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        enc.write_h256(&self.txhash);
+        enc.write_u32_be(self.index);
+        Ok(())
+    }
+}
+
+impl crate::Decode for OutputId {
+    /// Decodes an OutputId from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if fewer than 36 bytes remain.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let txhash = dec.read_h256()?;
+        let index = dec.read_u32_be()?;
+        Ok(OutputId { txhash, index })
+    }
+}
+
 impl Encodeable for OutputId {
     /// Encodes the OutputId into a byte vector.
     ///
@@ -29,9 +51,10 @@ impl Encodeable for OutputId {
     ///
     /// A `Vec<u8>` containing the encoded OutputId.
     fn encode(&self) -> Vec<u8> {
-        let mut bytes = Vec::new();
-        bytes.extend_from_slice(self.txhash.as_bytes());
-        bytes.extend_from_slice(&self.index.to_be_bytes());
+        let mut bytes = Vec::with_capacity(OUTPUT_ID_BYTE_LENGTH);
+        let mut enc = Encoder::new(&mut bytes);
+        <OutputId as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
         bytes
     }
 }
@@ -54,14 +77,8 @@ impl Decodeable for OutputId {
     where
         Self: Sized,
     {
-        if bytes.len() < OUTPUT_ID_BYTE_LENGTH {
-            return Err(Error::FailedToDecode);
-        }
-
-        let txhash = H256::from_slice(&bytes[..32]);
-        let index = u32::from_be_bytes(bytes[32..36].try_into().unwrap());
-
-        Ok(OutputId { txhash, index })
+        let mut dec = Decoder::new(bytes);
+        <OutputId as crate::Decode>::decode(&mut dec)
     }
 }
 
@@ -84,6 +101,39 @@ impl ByteLength for Nullifier {
     }
 }
 
+impl crate::Encode for Nullifier {
+    /// Encodes the Nullifier into `enc`.
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        match self {
+            Nullifier::Private(nullifier) => {
+                enc.write_u8(1);
+                enc.write_h256(nullifier);
+            }
+            Nullifier::Public(output_id) => {
+                enc.write_u8(2);
+                <OutputId as crate::Encode>::encode(output_id, enc)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl crate::Decode for Nullifier {
+    /// Decodes a Nullifier from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if the input is exhausted, or
+    /// `Error::UnsupportedNullifierType` if the discriminant is unrecognized.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        match dec.read_u8()? {
+            1 => Ok(Nullifier::Private(dec.read_h256()?)),
+            2 => Ok(Nullifier::Public(<OutputId as crate::Decode>::decode(dec)?)),
+            _ => Err(Error::UnsupportedNullifierType),
+        }
+    }
+}
+
 impl Encodeable for Nullifier {
     /// Encodes the Nullifier into a byte vector.
     ///
@@ -91,20 +141,11 @@ impl Encodeable for Nullifier {
     ///
     /// A `Vec<u8>` containing the encoded Nullifier.
     fn encode(&self) -> Vec<u8> {
-        match self {
-            Nullifier::Private(nullifier) => {
-                let mut bytes = Vec::with_capacity(33);
-                bytes.push(1u8);
-                bytes.extend_from_slice(nullifier.as_bytes());
-                bytes
-            }
-            Nullifier::Public(output_id) => {
-                let mut bytes = Vec::with_capacity(37);
-                bytes.push(2u8);
-                bytes.extend(output_id.encode());
-                bytes
-            }
-        }
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <Nullifier as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
     }
 }
 
@@ -126,25 +167,8 @@ impl Decodeable for Nullifier {
     where
         Self: Sized,
     {
-        if bytes.is_empty() {
-            return Err(Error::FailedToDecode);
-        }
-
-        match bytes[0] {
-            1 => {
-                if bytes.len() < 33 {
-                    return Err(Error::FailedToDecode);
-                }
-
-                let nullifier = H256::from_slice(&bytes[1..33]);
-                Ok(Nullifier::Private(nullifier))
-            }
-            2 => {
-                let output_id = OutputId::decode(&bytes[1..])?;
-                Ok(Nullifier::Public(output_id))
-            }
-            _ => Err(Error::UnsupportedNullifierType),
-        }
+        let mut dec = Decoder::new(bytes);
+        <Nullifier as crate::Decode>::decode(&mut dec)
     }
 }
 
@@ -1,6 +1,8 @@
 use alloc::vec::Vec;
 
-use crate::{ByteLength, Commitment, Decodeable, Encodeable, Error, Nullifier, Result};
+use crate::{
+    ByteLength, Commitment, Decodeable, Decoder, Encodeable, Encoder, Nullifier, Result, VarInt,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Transaction {
@@ -8,34 +10,35 @@ pub struct Transaction {
     pub outputs: Vec<Commitment>,
 }
 
-impl Encodeable for Transaction {
-    fn encode(&self) -> Vec<u8> {
-        let mut bytes = Vec::new();
-
-        // Encode inputs length (2 bytes)
-        bytes.extend_from_slice(&(self.inputs.len() as u16).to_be_bytes());
+impl crate::Encode for Transaction {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        // Encode inputs length (varint)
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.inputs.len()), enc)?;
         // Encode inputs
         for input in &self.inputs {
-            bytes.extend_from_slice(&input.encode());
+            <Nullifier as crate::Encode>::encode(input, enc)?;
         }
 
-        // Encode outputs length (2 bytes)
-        bytes.extend_from_slice(&(self.outputs.len() as u16).to_be_bytes());
+        // Encode outputs length (varint)
+        <VarInt as crate::Encode>::encode(&VarInt::from(self.outputs.len()), enc)?;
         // Encode outputs
         for output in &self.outputs {
-            bytes.extend_from_slice(&output.encode());
+            <Commitment as crate::Encode>::encode(output, enc)?;
         }
 
-        bytes
+        Ok(())
     }
 }
 
 impl ByteLength for Transaction {
     fn byte_length(&self) -> usize {
-        self.inputs
-            .iter()
-            .map(|input| input.byte_length())
-            .sum::<usize>()
+        VarInt::from(self.inputs.len()).byte_length()
+            + self
+                .inputs
+                .iter()
+                .map(|input| input.byte_length())
+                .sum::<usize>()
+            + VarInt::from(self.outputs.len()).byte_length()
             + self
                 .outputs
                 .iter()
@@ -44,52 +47,64 @@ impl ByteLength for Transaction {
     }
 }
 
-impl Decodeable for Transaction {
-    fn decode(bytes: &[u8]) -> Result<Self> {
-        let mut inputs = Vec::new();
-        let mut outputs = Vec::new();
-
-        let mut cursor = 0;
-
-        // Parse length of inputs (2 bytes)
-        if bytes.len() < 2 {
-            return Err(Error::FailedToDecode);
+// The smallest possible encoding of a Nullifier/Commitment (the private
+// variant: a 1-byte tag plus a 32-byte H256), used to cap pre-allocation
+// below so a maliciously large declared count can't request an oversized
+// `Vec::with_capacity` before any element is actually read.
+const MIN_NULLIFIER_BYTE_LENGTH: usize = 33;
+const MIN_COMMITMENT_BYTE_LENGTH: usize = 33;
+
+impl crate::Decode for Transaction {
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        // Parse length of inputs (varint)
+        let inputs_len = <VarInt as crate::Decode>::decode(dec)?;
+        log::debug!("inputs_len: {}", inputs_len.0);
+
+        // Parse inputs. Capacity is clamped to what could actually fit in
+        // the remaining bytes, since inputs_len comes straight off the wire.
+        let inputs_capacity =
+            inputs_len.as_usize().min(dec.remaining().len() / MIN_NULLIFIER_BYTE_LENGTH);
+        let mut inputs = Vec::with_capacity(inputs_capacity);
+        for _ in 0..inputs_len.as_usize() {
+            inputs.push(<Nullifier as crate::Decode>::decode(dec)?);
         }
-        let inputs_len = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
-        cursor += 2;
-
-        log::debug!("inputs_len: {}", inputs_len);
 
-        // Parse inputs
-        for _ in 0..inputs_len {
-            let input = Nullifier::decode(&bytes[cursor..])?;
-            cursor += input.byte_length();
-            inputs.push(input);
-        }
+        // Parse length of outputs (varint)
+        let outputs_len = <VarInt as crate::Decode>::decode(dec)?;
+        log::debug!("outputs_len: {}", outputs_len.0);
 
-        // Parse length of outputs (2 bytes)
-        if bytes.len() < cursor + 2 {
-            return Err(Error::FailedToDecode);
+        // Parse outputs, capacity-clamped for the same reason as inputs above.
+        let outputs_capacity =
+            outputs_len.as_usize().min(dec.remaining().len() / MIN_COMMITMENT_BYTE_LENGTH);
+        let mut outputs = Vec::with_capacity(outputs_capacity);
+        for _ in 0..outputs_len.as_usize() {
+            outputs.push(<Commitment as crate::Decode>::decode(dec)?);
         }
-        let outputs_len = u16::from_be_bytes([bytes[cursor], bytes[cursor + 1]]) as usize;
-        cursor += 2;
 
-        log::debug!("outputs_len: {}", outputs_len);
+        Ok(Transaction { inputs, outputs })
+    }
+}
 
-        // Parse outputs
-        for _ in 0..outputs_len {
-            let output = Commitment::decode(&bytes[cursor..])?;
-            cursor += output.byte_length();
-            outputs.push(output);
-        }
+impl Encodeable for Transaction {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <Transaction as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
 
-        Ok(Transaction { inputs, outputs })
+impl Decodeable for Transaction {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut dec = Decoder::new(bytes);
+        <Transaction as crate::Decode>::decode(&mut dec)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{OutputId, UnspentOutput};
+    use crate::{Error, Output, OutputId, PrivateCommitment};
 
     use super::*;
     use primitive_types::{H160, H256, U256};
@@ -113,8 +128,8 @@ mod tests {
             ]
             .to_vec(),
             outputs: [
-                Commitment::Private(H256::random()),
-                Commitment::Public(UnspentOutput {
+                Commitment::Private(PrivateCommitment(H256::random())),
+                Commitment::Public(Output {
                     amount: U256::from(1000u32),
                     asset: H256::random(),
                     owner: H160::random(),
@@ -148,4 +163,36 @@ mod tests {
             Err(Error::FailedToDecode)
         );
     }
+
+    #[test]
+    fn test_transaction_encode_decode_crosses_varint_boundary() {
+        // 300 inputs crosses the 0xFD VarInt threshold (253), exercising the
+        // multi-byte length-prefix form the u16-prefix encoding never had.
+        let inputs: Vec<Nullifier> = (0..300)
+            .map(|index| {
+                Nullifier::Public(OutputId {
+                    txhash: H256::random(),
+                    index,
+                })
+            })
+            .collect();
+        let transaction = Transaction {
+            inputs,
+            outputs: Vec::new(),
+        };
+
+        let encoded = transaction.encode();
+        assert_eq!(encoded.len(), transaction.byte_length());
+
+        let decoded = Transaction::decode(&encoded).unwrap();
+        assert_eq!(transaction, decoded);
+    }
+
+    #[test]
+    fn test_transaction_decode_rejects_oversized_declared_length() {
+        // A VarInt declaring ~2^56 inputs with no backing bytes must fail
+        // cleanly instead of aborting on an oversized Vec::with_capacity.
+        let hostile = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+        assert_eq!(Transaction::decode(&hostile), Err(Error::FailedToDecode));
+    }
 }
@@ -1,7 +1,9 @@
 use alloc::vec::Vec;
 use primitive_types::H256;
 
-use crate::{ByteLength, Decodeable, Decryptor, Encodeable, Encryptor, Output, Result};
+use crate::{
+    ByteLength, Decodeable, Decoder, Decryptor, Encodeable, Encoder, Encryptor, Output, Result,
+};
 
 pub struct UnencryptedOutput {
     pub output: Output,
@@ -14,20 +16,41 @@ impl ByteLength for UnencryptedOutput {
     }
 }
 
+impl crate::Encode for UnencryptedOutput {
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        <Output as crate::Encode>::encode(&self.output, enc)?;
+        enc.write_h256(&self.salt);
+        Ok(())
+    }
+}
+
+impl crate::Decode for UnencryptedOutput {
+    /// Decodes an UnencryptedOutput from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if fewer than 32 bytes remain for the salt.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        let output = <Output as crate::Decode>::decode(dec)?;
+        let salt = dec.read_h256()?;
+        Ok(UnencryptedOutput { output, salt })
+    }
+}
+
 impl Encodeable for UnencryptedOutput {
     fn encode(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(self.output.byte_length() + 32);
-        bytes.extend_from_slice(&self.output.encode());
-        bytes.extend_from_slice(self.salt.as_bytes());
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <UnencryptedOutput as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
         bytes
     }
 }
 
 impl Decodeable for UnencryptedOutput {
     fn decode(bytes: &[u8]) -> Result<Self> {
-        let output = Output::decode(bytes)?;
-        let salt = H256::from_slice(&bytes[output.byte_length()..]);
-        Ok(UnencryptedOutput { output, salt })
+        let mut dec = Decoder::new(bytes);
+        <UnencryptedOutput as crate::Decode>::decode(&mut dec)
     }
 }
 
@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use crate::{ByteLength, Decodeable, Encodeable, Encoder, Decoder, Error, Result};
+
+const VARINT_16: u8 = 0xFD;
+const VARINT_32: u8 = 0xFE;
+const VARINT_64: u8 = 0xFF;
+
+/// A Bitcoin-style CompactSize variable-length integer.
+///
+/// Values below `0xFD` encode as a single byte. Larger values are prefixed
+/// with a discriminant byte (`0xFD`, `0xFE`, or `0xFF`) followed by the
+/// value in 2, 4, or 8 little-endian bytes respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct VarInt(pub u64);
+
+impl VarInt {
+    /// Returns the value as a `usize`, for indexing into in-memory collections.
+    pub fn as_usize(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<u64> for VarInt {
+    fn from(value: u64) -> Self {
+        VarInt(value)
+    }
+}
+
+impl From<usize> for VarInt {
+    fn from(value: usize) -> Self {
+        VarInt(value as u64)
+    }
+}
+
+impl ByteLength for VarInt {
+    fn byte_length(&self) -> usize {
+        match self.0 {
+            0..=0xFC => 1,
+            0xFD..=0xFFFF => 3,
+            0x1_0000..=0xFFFF_FFFF => 5,
+            _ => 9,
+        }
+    }
+}
+
+impl crate::Encode for VarInt {
+    /// Encodes the VarInt using the shortest valid form.
+    fn encode(&self, enc: &mut Encoder) -> Result<()> {
+        match self.0 {
+            0..=0xFC => enc.write_u8(self.0 as u8),
+            0xFD..=0xFFFF => {
+                enc.write_u8(VARINT_16);
+                enc.write_bytes(&(self.0 as u16).to_le_bytes());
+            }
+            0x1_0000..=0xFFFF_FFFF => {
+                enc.write_u8(VARINT_32);
+                enc.write_bytes(&(self.0 as u32).to_le_bytes());
+            }
+            _ => {
+                enc.write_u8(VARINT_64);
+                enc.write_bytes(&self.0.to_le_bytes());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl crate::Decode for VarInt {
+    /// Decodes a VarInt from `dec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if the input is truncated, or if the
+    /// value is encoded in a longer form than its shortest valid one.
+    fn decode(dec: &mut Decoder) -> Result<Self> {
+        match dec.read_u8()? {
+            VARINT_16 => {
+                let value = u16::from_le_bytes(dec.read_bytes(2)?.try_into().unwrap()) as u64;
+                if value < VARINT_16 as u64 {
+                    return Err(Error::FailedToDecode);
+                }
+                Ok(VarInt(value))
+            }
+            VARINT_32 => {
+                let value = u32::from_le_bytes(dec.read_bytes(4)?.try_into().unwrap()) as u64;
+                if value <= 0xFFFF {
+                    return Err(Error::FailedToDecode);
+                }
+                Ok(VarInt(value))
+            }
+            VARINT_64 => {
+                let value = u64::from_le_bytes(dec.read_bytes(8)?.try_into().unwrap());
+                if value <= 0xFFFF_FFFF {
+                    return Err(Error::FailedToDecode);
+                }
+                Ok(VarInt(value))
+            }
+            discriminant => Ok(VarInt(discriminant as u64)),
+        }
+    }
+}
+
+impl Encodeable for VarInt {
+    /// Encodes the VarInt into a byte vector using the shortest valid form.
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_length());
+        let mut enc = Encoder::new(&mut bytes);
+        <VarInt as crate::Encode>::encode(self, &mut enc)
+            .expect("encoding into an in-memory buffer is infallible");
+        bytes
+    }
+}
+
+impl Decodeable for VarInt {
+    /// Decodes a byte slice into a VarInt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FailedToDecode` if the input is truncated, or if the
+    /// value is encoded in a longer form than its shortest valid one.
+    fn decode(bytes: &[u8]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut dec = Decoder::new(bytes);
+        <VarInt as crate::Decode>::decode(&mut dec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_roundtrip_boundaries() {
+        for value in [
+            0u64,
+            1,
+            0xFC,
+            0xFD,
+            0xFFFF,
+            0x1_0000,
+            0xFFFF_FFFF,
+            0x1_0000_0000,
+            u64::MAX,
+        ] {
+            let varint = VarInt(value);
+            let encoded = varint.encode();
+            assert_eq!(encoded.len(), varint.byte_length());
+            let decoded = VarInt::decode(&encoded).unwrap();
+            assert_eq!(varint, decoded);
+        }
+    }
+
+    #[test]
+    fn test_varint_rejects_non_canonical() {
+        // 0xFD followed by a value that fits in a single byte.
+        assert_eq!(
+            VarInt::decode(&[0xFD, 0xFC, 0x00]),
+            Err(Error::FailedToDecode)
+        );
+        // 0xFE followed by a value that fits in the 0xFD form.
+        assert_eq!(
+            VarInt::decode(&[0xFE, 0xFF, 0xFF, 0x00, 0x00]),
+            Err(Error::FailedToDecode)
+        );
+        // 0xFF followed by a value that fits in the 0xFE form.
+        let mut bytes = alloc::vec![0xFFu8];
+        bytes.extend_from_slice(&0xFFFF_FFFFu64.to_le_bytes());
+        assert_eq!(VarInt::decode(&bytes), Err(Error::FailedToDecode));
+    }
+
+    #[test]
+    fn test_varint_rejects_truncated() {
+        assert_eq!(VarInt::decode(&[]), Err(Error::FailedToDecode));
+        assert_eq!(VarInt::decode(&[0xFD, 0x00]), Err(Error::FailedToDecode));
+        assert_eq!(VarInt::decode(&[0xFE, 0x00, 0x00]), Err(Error::FailedToDecode));
+    }
+}